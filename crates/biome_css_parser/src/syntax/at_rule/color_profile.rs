@@ -1,15 +1,28 @@
 use crate::lexer::CssLexContext;
 use crate::parser::CssParser;
-use crate::syntax::blocks::parse_or_recover_declaration_list_block;
 use crate::syntax::parse_custom_identifier;
+use crate::syntax::parse_declaration::parse_declaration;
 use crate::syntax::parse_error::expected_non_css_wide_keyword_identifier;
 use biome_css_syntax::CssSyntaxKind::*;
 use biome_css_syntax::{CssSyntaxKind, T};
+use biome_parser::diagnostic::expected_node;
+use biome_parser::parse_recovery::RecoveryResult;
 use biome_parser::parse_recovery::ParseRecovery;
 use biome_parser::parsed_syntax::ParsedSyntax::Present;
 use biome_parser::prelude::ParsedSyntax::Absent;
 use biome_parser::prelude::*;
 
+/// The only descriptors the `@color-profile` declaration list is allowed to contain.
+///
+/// <https://drafts.csswg.org/css-color-5/#color-profile-desc>
+const KNOWN_COLOR_PROFILE_DESCRIPTORS: &[&str] = &["src", "rendering-intent", "components"];
+
+// This file references `T![device_cmyk]`, `CSS_COLOR_PROFILE_DEVICE_CMYK_NAME`, and
+// `CSS_DECLARATION_LIST_BLOCK`. Defining them is a `biome_css_syntax` grammar/codegen change
+// (the `.ungram` entry plus the generated `SyntaxKind`/token output) that lives outside this
+// crate and isn't part of this checkout, so it isn't included here; this parser change assumes
+// that grammar work lands alongside it.
+
 #[inline]
 pub(crate) fn is_color_profile_at_rule(p: &mut CssParser) -> bool {
     p.at(T![color_profile])
@@ -25,26 +38,116 @@ pub(crate) fn parse_color_profile_at_rule(p: &mut CssParser) -> ParsedSyntax {
 
     p.bump(T![color_profile]);
 
-    // TODO: This should actually be `<dashed-ident> | device-cmyk`.
-    let kind = if parse_custom_identifier(p, CssLexContext::Regular)
-        .or_recover(
-            p,
-            &ParseRecovery::new(CSS_BOGUS, COLOR_PROFILE_RECOVERY_SET)
-                .enable_recovery_on_line_break(),
-            expected_non_css_wide_keyword_identifier,
-        )
-        .is_ok()
-    {
+    let kind = if parse_color_profile_name(p).is_ok() {
         CSS_COLOR_PROFILE_AT_RULE
     } else {
         CSS_BOGUS_AT_RULE
     };
 
-    if parse_or_recover_declaration_list_block(p).is_err() {
+    if parse_color_profile_declaration_list_block(p, is_known_color_profile_descriptor).is_absent() {
         return Present(m.complete(p, CSS_BOGUS_AT_RULE));
     }
 
     Present(m.complete(p, kind))
 }
 
+/// The `@color-profile` name is either a `<dashed-ident>` (a custom profile name) or the
+/// `device-cmyk` keyword. The keyword is bumped as its own token kind so that downstream
+/// passes can tell the two name shapes apart without inspecting the text of a generic
+/// `CSS_CUSTOM_IDENTIFIER` node. Any other bare identifier is rejected with a targeted
+/// diagnostic, while still recovering into a bogus node so that the rest of the at-rule can
+/// be parsed.
+#[inline]
+fn parse_color_profile_name(p: &mut CssParser) -> RecoveryResult {
+    if p.at(T![device_cmyk]) {
+        let m = p.start();
+        p.bump(T![device_cmyk]);
+        return Ok(m.complete(p, CSS_COLOR_PROFILE_DEVICE_CMYK_NAME));
+    }
+
+    if is_at_dashed_identifier(p) {
+        return parse_custom_identifier(p, CssLexContext::Regular).or_recover(
+            p,
+            &ParseRecovery::new(CSS_BOGUS, COLOR_PROFILE_RECOVERY_SET)
+                .enable_recovery_on_line_break(),
+            expected_non_css_wide_keyword_identifier,
+        );
+    }
+
+    // Anything else — including a bare, non-dashed identifier like `srgb` — is not a valid
+    // `@color-profile` name. Force a diagnostic and recover into a bogus node instead of
+    // letting `parse_custom_identifier` silently accept it.
+    Absent.or_recover(
+        p,
+        &ParseRecovery::new(CSS_BOGUS, COLOR_PROFILE_RECOVERY_SET).enable_recovery_on_line_break(),
+        expected_color_profile_name,
+    )
+}
+
+#[inline]
+fn is_at_dashed_identifier(p: &CssParser) -> bool {
+    p.at(CSS_IDENTIFIER) && p.cur_text().starts_with("--")
+}
+
+fn expected_color_profile_name(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_node(
+        "a dashed identifier (e.g. `--my-profile`) or the keyword `device-cmyk`",
+        range,
+        p,
+    )
+}
+
+/// Parses the `{ <declaration>* }` block of a `@color-profile` rule, completing it as its own
+/// `CSS_DECLARATION_LIST_BLOCK` node (never the calling at-rule's kind, so the generated
+/// `block()` accessor on the at-rule always resolves). Each declaration is parsed through the
+/// real [`parse_declaration`] grammar (property / `:` / value / optional `!important`), so the
+/// tree shape matches every other CSS declaration list; only the descriptor name is checked
+/// against [`is_known_descriptor`] first, so unknown descriptors still get a diagnostic while
+/// staying in the tree exactly like known ones.
+#[inline]
+fn parse_color_profile_declaration_list_block(
+    p: &mut CssParser,
+    is_known_descriptor: impl Fn(&str) -> bool,
+) -> ParsedSyntax {
+    if !p.at(T!['{']) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T!['{']);
+
+    while !p.at(T!['}']) && !p.at(EOF) {
+        if p.at(CSS_IDENTIFIER) && !is_known_descriptor(p.cur_text()) {
+            p.error(expected_color_profile_descriptor(p, p.cur_range()));
+        }
+
+        let declaration_start = p.cur_range();
+        if parse_declaration(p).is_absent() && p.cur_range() == declaration_start {
+            // No progress was made (e.g. an unexpected token right after `{`); bump it as
+            // bogus so the loop can't spin forever.
+            p.err_and_bump(expected_color_profile_descriptor(p, p.cur_range()), CSS_BOGUS);
+        }
+    }
+
+    if p.expect(T!['}']) {
+        Present(m.complete(p, CSS_DECLARATION_LIST_BLOCK))
+    } else {
+        Present(m.complete(p, CSS_BOGUS_BLOCK))
+    }
+}
+
+fn is_known_color_profile_descriptor(name: &str) -> bool {
+    KNOWN_COLOR_PROFILE_DESCRIPTORS
+        .iter()
+        .any(|descriptor| descriptor.eq_ignore_ascii_case(name))
+}
+
+fn expected_color_profile_descriptor(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_node(
+        "one of the `@color-profile` descriptors: `src`, `rendering-intent`, or `components`",
+        range,
+        p,
+    )
+}
+
 const COLOR_PROFILE_RECOVERY_SET: TokenSet<CssSyntaxKind> = token_set![T!['{']];
@@ -1,7 +1,15 @@
+use crate::JsRuleAction;
 use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
 use biome_console::markup;
-use biome_js_syntax::{AnyJsMemberExpression, JsCallExpression};
-use biome_rowan::AstNode;
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    AnyJsArrowFunctionParameters, AnyJsBinding, AnyJsExpression, AnyJsFormalParameter,
+    AnyJsFunctionBody, AnyJsMemberExpression, AnyJsParameter, AnyJsStatement, JsCallExpression,
+    JsExpressionStatement, JsIdentifierBinding, JsParameters, JsReturnStatement, JsStatementList,
+    JsSyntaxKind, JsSyntaxNode, T,
+};
+use biome_rowan::{AstNode, AstSeparatedList, BatchMutationExt};
 
 declare_rule! {
     /// Prefer `for...of` statement instead of `Array.forEach`.
@@ -52,9 +60,22 @@ declare_rule! {
     }
 }
 
+pub(crate) struct NoForEachState {
+    /// What the action needs to rewrite the call, when the `forEach` callback is shaped in a way
+    /// we know how to rewrite.
+    fix: Option<ForEachFix>,
+}
+
+struct ForEachFix {
+    statement: JsExpressionStatement,
+    iterable: AnyJsExpression,
+    param: JsIdentifierBinding,
+    body: AnyJsFunctionBody,
+}
+
 impl Rule for NoForEach {
     type Query = Ast<JsCallExpression>;
-    type State = ();
+    type State = NoForEachState;
     type Signals = Option<Self::State>;
     type Options = ();
 
@@ -62,7 +83,14 @@ impl Rule for NoForEach {
         let node = ctx.query();
         let member_expression =
             AnyJsMemberExpression::cast_ref(node.callee().ok()?.omit_parentheses().syntax())?;
-        (member_expression.member_name()?.text() == "forEach").then_some(())
+
+        if member_expression.member_name()?.text() != "forEach" {
+            return None;
+        }
+
+        Some(NoForEachState {
+            fix: build_fix(node),
+        })
     }
 
     fn diagnostic(ctx: &RuleContext<Self>, _state: &Self::State) -> Option<RuleDiagnostic> {
@@ -78,4 +106,303 @@ impl Rule for NoForEach {
             <Emphasis>"forEach"</Emphasis>" could lead to performance issues when working with large arrays. When combined with functions like .filter or .map, this causes multiple iterations over the same type."
         }))
     }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let fix = state.fix.as_ref()?;
+
+        let mut mutation = ctx.root().begin();
+
+        // `forEach` callbacks `return` to skip to the next element; a `for...of` loop does the
+        // same with `continue`. This has to happen on a standalone copy of the body *before* it
+        // is nested inside the new `for...of` we build below: the mutation we register at the
+        // end replaces the whole enclosing statement wholesale, so any `replace_node` calls
+        // targeting nodes reused inside that replacement would never be picked up.
+        let body = match fix.body.clone() {
+            AnyJsFunctionBody::JsFunctionBody(block) => rebuild_statement_list(&block.statements())?,
+            AnyJsFunctionBody::AnyJsExpression(expression) => {
+                let statement = make::js_expression_statement(expression).build();
+                make::js_statement_list([AnyJsStatement::JsExpressionStatement(statement)])
+            }
+        };
+
+        let declarator = make::js_variable_declarator(
+            biome_js_syntax::AnyJsBindingPattern::AnyJsBinding(AnyJsBinding::JsIdentifierBinding(
+                fix.param.clone(),
+            )),
+        )
+        .build();
+        let declarator_list =
+            make::js_variable_declarator_list([declarator], []);
+        let for_declaration =
+            make::js_for_variable_declaration(make::token(T![const]), declarator_list);
+
+        let block = make::js_block_statement(
+            make::token(T!['{']),
+            body,
+            make::token(T!['}']),
+        )
+        .build();
+
+        let for_of = make::js_for_of_statement(
+            make::token(T![for]),
+            make::token(T!['(']),
+            biome_js_syntax::AnyJsForInOrOfInitializer::JsForVariableDeclaration(for_declaration),
+            make::token(T![of]),
+            fix.iterable.clone(),
+            make::token(T![')']),
+            AnyJsStatement::JsBlockStatement(block),
+        )
+        .build();
+
+        mutation.replace_node(
+            AnyJsStatement::JsExpressionStatement(fix.statement.clone()),
+            AnyJsStatement::JsForOfStatement(for_of),
+        );
+
+        Some(JsRuleAction {
+            category: biome_analyze::ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Convert this "<Emphasis>"forEach"</Emphasis>" call to a "<Emphasis>"for...of"</Emphasis>" loop" }.to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Figures out whether `node` (a call to `forEach`) can be mechanically rewritten into a
+/// `for...of` loop, and gathers everything the `action` needs to do so.
+///
+/// We only offer the fix when:
+/// - the call has a single `thisArg`-less argument,
+/// - that argument is an arrow or function expression with exactly one, simple identifier
+///   parameter (no `index`/`array` second parameter),
+/// - the callback body doesn't use `await`, `yield`, `this` or `arguments`, since those can't
+///   be mechanically lifted into the enclosing scope,
+/// - the call is itself an expression statement, so there is a statement to replace.
+fn build_fix(node: &JsCallExpression) -> Option<ForEachFix> {
+    let member_expression =
+        AnyJsMemberExpression::cast_ref(node.callee().ok()?.omit_parentheses().syntax())?;
+    let iterable = member_expression.object().ok()?;
+
+    let statement = node
+        .syntax()
+        .ancestors()
+        .find_map(JsExpressionStatement::cast)?;
+    if statement.expression().ok()?.syntax() != node.syntax()
+        && statement.expression().ok()?.omit_parentheses().syntax() != node.syntax()
+    {
+        return None;
+    }
+
+    let args = node.arguments().ok()?;
+    let mut items = args.args().iter();
+    let callback = items.next()?.ok()?;
+    if items.next().is_some() {
+        // A second argument is a `thisArg`, which has no clean `for...of` equivalent.
+        return None;
+    }
+    let callback = callback.as_any_js_expression()?.clone();
+
+    let (param, body) = match callback {
+        AnyJsExpression::JsArrowFunctionExpression(arrow) => {
+            let param = single_identifier_param(&arrow.parameters().ok()?)?;
+            (param, arrow.body().ok()?)
+        }
+        AnyJsExpression::JsFunctionExpression(function) => {
+            let param = single_identifier_param_from_parameters(&function.parameters().ok()?)?;
+            (
+                param,
+                AnyJsFunctionBody::JsFunctionBody(function.body().ok()?),
+            )
+        }
+        _ => return None,
+    };
+
+    if uses_outer_this_or_control_flow(body.syntax()) {
+        return None;
+    }
+
+    // Every top-level `return` needs to become a `continue`. We only know how to do that
+    // mechanically when the `return` sits directly in the body or behind `if`/`else` guard
+    // clauses (the common early-exit shape); bail on anything else (`switch`, loops, `try`,
+    // labeled statements, ...) rather than risk turning a `return` that skips the rest of the
+    // callback into one that also skips the rest of the loop's *other* iterations differently
+    // than intended.
+    let top_level_returns = top_level_return_statements(body.syntax());
+    if !top_level_returns
+        .iter()
+        .all(|return_statement| is_supported_return_context(return_statement, body.syntax()))
+    {
+        return None;
+    }
+
+    Some(ForEachFix {
+        statement,
+        iterable,
+        param,
+        body,
+    })
+}
+
+fn single_identifier_param(parameters: &AnyJsArrowFunctionParameters) -> Option<JsIdentifierBinding> {
+    match parameters {
+        AnyJsArrowFunctionParameters::AnyJsBinding(binding) => {
+            binding.as_js_identifier_binding().cloned()
+        }
+        AnyJsArrowFunctionParameters::JsParameters(parameters) => {
+            single_identifier_param_from_parameters(parameters)
+        }
+    }
+}
+
+fn single_identifier_param_from_parameters(parameters: &JsParameters) -> Option<JsIdentifierBinding> {
+    let mut items = parameters.items().iter();
+    let only_param = items.next()?.ok()?;
+    if items.next().is_some() {
+        return None;
+    }
+
+    let AnyJsParameter::AnyJsFormalParameter(AnyJsFormalParameter::JsFormalParameter(param)) =
+        only_param
+    else {
+        return None;
+    };
+
+    param
+        .binding()
+        .ok()?
+        .as_any_js_binding()?
+        .as_js_identifier_binding()
+        .cloned()
+}
+
+/// `await`, `yield`, `this` and `arguments` all refer to whatever the `forEach` callback closes
+/// over; none of them keep their meaning once lifted into the enclosing `for...of` loop, so we
+/// bail out of offering a fix when any of them appear in the callback body.
+fn uses_outer_this_or_control_flow(body: &JsSyntaxNode) -> bool {
+    body.descendants().any(|descendant| {
+        matches!(
+            descendant.kind(),
+            JsSyntaxKind::JS_AWAIT_EXPRESSION
+                | JsSyntaxKind::JS_YIELD_EXPRESSION
+                | JsSyntaxKind::JS_THIS_EXPRESSION
+        ) || descendant
+            .kind()
+            .eq(&JsSyntaxKind::JS_IDENTIFIER_EXPRESSION)
+            && descendant.text_trimmed() == "arguments"
+    })
+}
+
+/// Rebuilds `list`, turning every top-level `return;`/`return expr;` into a `continue;`.
+/// `build_fix` already guarantees every such `return` is only nested behind `if`/`else`/block,
+/// which are the only statement shapes rebuilt recursively here; everything else is reused as-is.
+fn rebuild_statement_list(list: &JsStatementList) -> Option<JsStatementList> {
+    let statements = list
+        .iter()
+        .map(|statement| rebuild_statement(&statement))
+        .collect::<Option<Vec<_>>>()?;
+    Some(make::js_statement_list(statements))
+}
+
+fn rebuild_statement(statement: &AnyJsStatement) -> Option<AnyJsStatement> {
+    match statement {
+        AnyJsStatement::JsReturnStatement(_) => Some(AnyJsStatement::JsContinueStatement(
+            make::js_continue_statement(make::token(T![continue])).build(),
+        )),
+        AnyJsStatement::JsBlockStatement(block) => {
+            let statements = rebuild_statement_list(&block.statements())?;
+            Some(AnyJsStatement::JsBlockStatement(
+                make::js_block_statement(
+                    block.l_curly_token().ok()?,
+                    statements,
+                    block.r_curly_token().ok()?,
+                )
+                .build(),
+            ))
+        }
+        AnyJsStatement::JsIfStatement(if_statement) => {
+            let consequent = rebuild_statement(&if_statement.consequent().ok()?)?;
+
+            let mut builder = make::js_if_statement(
+                if_statement.if_token().ok()?,
+                if_statement.l_paren_token().ok()?,
+                if_statement.test().ok()?,
+                if_statement.r_paren_token().ok()?,
+                consequent,
+            );
+
+            if let Ok(else_clause) = if_statement.else_clause() {
+                let alternate = rebuild_statement(&else_clause.alternate().ok()?)?;
+                builder = builder.with_else_clause(
+                    make::js_else_clause(else_clause.else_token().ok()?, alternate).build(),
+                );
+            }
+
+            Some(AnyJsStatement::JsIfStatement(builder.build()))
+        }
+        other => Some(other.clone()),
+    }
+}
+
+/// Collects every `return` statement reachable from `node` without descending into nested
+/// functions, which have their own `return`.
+fn top_level_return_statements(node: &JsSyntaxNode) -> Vec<JsReturnStatement> {
+    let mut result = Vec::new();
+    collect_top_level_returns(node, &mut result);
+    result
+}
+
+fn collect_top_level_returns(node: &JsSyntaxNode, out: &mut Vec<JsReturnStatement>) {
+    for child in node.children() {
+        if is_function_like(child.kind()) {
+            continue;
+        }
+
+        if let Some(return_statement) = JsReturnStatement::cast_ref(&child) {
+            out.push(return_statement);
+            continue;
+        }
+
+        collect_top_level_returns(&child, out);
+    }
+}
+
+/// Whether `return_statement` is only nested in `if`/`else` guard clauses and blocks on its way
+/// up to `scope_root` (the callback body). Those are the only shapes `rebuild_statement` above
+/// knows how to rebuild with `continue` in place of `return`.
+fn is_supported_return_context(return_statement: &JsReturnStatement, scope_root: &JsSyntaxNode) -> bool {
+    let mut node = return_statement.syntax().parent();
+
+    while let Some(current) = node {
+        if &current == scope_root {
+            return true;
+        }
+
+        match current.kind() {
+            JsSyntaxKind::JS_STATEMENT_LIST
+            | JsSyntaxKind::JS_BLOCK_STATEMENT
+            | JsSyntaxKind::JS_IF_STATEMENT
+            | JsSyntaxKind::JS_ELSE_CLAUSE => {
+                node = current.parent();
+            }
+            _ => return false,
+        }
+    }
+
+    false
+}
+
+fn is_function_like(kind: JsSyntaxKind) -> bool {
+    matches!(
+        kind,
+        JsSyntaxKind::JS_FUNCTION_EXPRESSION
+            | JsSyntaxKind::JS_ARROW_FUNCTION_EXPRESSION
+            | JsSyntaxKind::JS_FUNCTION_DECLARATION
+            | JsSyntaxKind::JS_METHOD_CLASS_MEMBER
+            | JsSyntaxKind::JS_GETTER_CLASS_MEMBER
+            | JsSyntaxKind::JS_SETTER_CLASS_MEMBER
+            | JsSyntaxKind::JS_CONSTRUCTOR_CLASS_MEMBER
+            | JsSyntaxKind::JS_METHOD_OBJECT_MEMBER
+            | JsSyntaxKind::JS_GETTER_OBJECT_MEMBER
+            | JsSyntaxKind::JS_SETTER_OBJECT_MEMBER
+    )
 }
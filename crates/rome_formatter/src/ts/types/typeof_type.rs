@@ -1,7 +1,52 @@
-use crate::{FormatElement, FormatResult, Formatter, ToFormatElement};
-use rslint_parser::{ast::TsTypeofType, AstNode};
+use crate::{
+    format_elements, space_token, token, FormatElement, FormatResult, Formatter, ToFormatElement,
+};
+use rslint_parser::{
+    ast::{TsIndexedAccessType, TsTypeofType},
+    AstNode,
+};
+
+/// Formats a `typeof` type query, e.g. `typeof foo`, `typeof import("mod").Member` or the
+/// TS 4.7 instantiation expression form `typeof fn<string>`.
+///
+/// The `typeof` keyword is always followed by a single entity name, which already knows how to
+/// format itself (including the `import(...)` qualifier form), so this impl only has to join the
+/// keyword, the name, and the optional type arguments together, then add parentheses back if
+/// [`needs_parens`] says the parent type requires them.
 impl ToFormatElement for TsTypeofType {
     fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
-        Ok(formatter.format_verbatim(self.syntax()))
+        let typeof_token = formatter.format_token(&self.typeof_token()?)?;
+        let entity_name = self.entity_name()?.to_format_element(formatter)?;
+
+        let type_args = match self.type_args() {
+            Some(type_args) => type_args.to_format_element(formatter)?,
+            None => FormatElement::Empty,
+        };
+
+        let query = format_elements![typeof_token, space_token(), entity_name, type_args];
+
+        if needs_parens(self) {
+            Ok(format_elements![token("("), query, token(")")])
+        } else {
+            Ok(query)
+        }
     }
-}
\ No newline at end of file
+}
+
+/// Whether `typeof_type` must be wrapped in parentheses to keep its meaning in its parent type.
+///
+/// A `typeof` query binds like any other primary type, so union/intersection members never need
+/// parentheses around one. The one position that does is being the object of an indexed-access
+/// type (`(typeof foo)["key"]`): without parentheses, `["key"]` would instead be parsed as part
+/// of the query's own entity name path rather than indexing the type the query produces. This
+/// computes that need structurally, from the parent node, rather than only ever reproducing
+/// whatever parentheses happened to already be in the source.
+fn needs_parens(typeof_type: &TsTypeofType) -> bool {
+    let Some(parent) = typeof_type.syntax().parent() else {
+        return false;
+    };
+
+    TsIndexedAccessType::cast(parent)
+        .and_then(|indexed| indexed.object_type())
+        .map_or(false, |object| object.syntax() == typeof_type.syntax())
+}
@@ -6,11 +6,13 @@ use rome_console::markup;
 use rome_diagnostics::Applicability;
 use rome_js_semantic::{AllReferencesExtensions, Reference};
 use rome_js_syntax::{
-    JsAnyExpression, JsAnyLiteralExpression, JsAnyRoot, JsIdentifierBinding,
-    JsIdentifierExpression, JsLanguage, JsStringLiteralExpression, JsVariableDeclaration,
-    JsVariableDeclarator, JsVariableDeclaratorList, JsVariableStatement,
+    JsAnyExpression, JsAnyLiteralExpression, JsAnyRoot, JsExportVariableClause,
+    JsIdentifierBinding, JsIdentifierExpression, JsLanguage, JsPropertyObjectMember,
+    JsShorthandPropertyObjectMember, JsSyntaxKind, JsVariableDeclaration, JsVariableDeclarator,
+    JsVariableDeclaratorList, JsVariableStatement,
 };
 use rome_rowan::{AstNode, AstSeparatedList, BatchMutation, BatchMutationExt, SyntaxNodeCast};
+use serde::{Deserialize, Serialize};
 
 declare_rule! {
     /// Disallow the use of constants which its value is the upper-case version of its name.
@@ -30,27 +32,49 @@ declare_rule! {
     }
 }
 
-/// Check for
-/// a = "a" (true)
-/// a = "b" (false)
-fn is_id_and_string_literal_inner_text_equal(
+/// Options for the rule [`NoShoutyConstants`].
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct NoShoutyConstantsOptions {
+    /// When `true`, also flags numeric and boolean literals whose textual value matches the
+    /// identifier name (e.g. `const TRUE = true`), instead of only string literals.
+    /// Defaults to `false`.
+    #[serde(default)]
+    pub check_all_literals: bool,
+}
+
+/// Check whether `declarator`'s identifier and initializer are redundant, e.g.
+/// `a = "a"` (true), `a = "b"` (false). Numeric and boolean literals are only considered when
+/// `check_all_literals` is enabled, since inlining them is a much more common, intentional
+/// pattern (e.g. `const ZERO = 0`).
+fn is_id_and_literal_inner_text_equal(
     declarator: &JsVariableDeclarator,
-) -> Option<(JsIdentifierBinding, JsStringLiteralExpression)> {
+    check_all_literals: bool,
+) -> Option<(JsIdentifierBinding, JsAnyLiteralExpression)> {
     let id = declarator.id().ok()?;
     let id = id.as_js_any_binding()?.as_js_identifier_binding()?;
     let id_text = id.syntax().text_trimmed();
 
     let expression = declarator.initializer()?.expression().ok()?;
-    let literal = expression
-        .as_js_any_literal_expression()?
-        .as_js_string_literal_expression()?;
-    let literal_text = literal.inner_string_text();
+    let literal = expression.as_js_any_literal_expression()?;
 
-    if id_text == literal_text {
-        Some((id.clone(), literal.clone()))
-    } else {
-        None
-    }
+    let is_match = match &literal {
+        JsAnyLiteralExpression::JsStringLiteralExpression(literal) => {
+            id_text == literal.inner_string_text()
+        }
+        // Identifiers are conventionally SCREAMING_SNAKE_CASE while `true`/`false`/numbers are
+        // lowercase, so the comparison has to fold case to ever match the documented
+        // `const TRUE = true` case.
+        JsAnyLiteralExpression::JsNumberLiteralExpression(literal) if check_all_literals => id_text
+            .to_string()
+            .eq_ignore_ascii_case(&literal.syntax().text_trimmed().to_string()),
+        JsAnyLiteralExpression::JsBooleanLiteralExpression(literal) if check_all_literals => id_text
+            .to_string()
+            .eq_ignore_ascii_case(&literal.syntax().text_trimmed().to_string()),
+        _ => false,
+    };
+
+    is_match.then(|| (id.clone(), literal.clone()))
 }
 
 /// Removes the declarator, and:
@@ -100,9 +124,45 @@ fn remove_declarator(
     Some(())
 }
 
+/// Whether `declarator` is part of the module's public surface, i.e. `export const FOO = ...`.
+/// Removing such a declarator would change what the module exports, so it is never safe to
+/// inline automatically.
+fn is_exported(declarator: &JsVariableDeclarator) -> bool {
+    declarator
+        .parent::<JsVariableDeclaratorList>()
+        .and_then(|list| list.parent::<JsVariableDeclaration>())
+        .and_then(|declaration| declaration.parent::<JsVariableStatement>())
+        .and_then(|statement| statement.parent::<JsExportVariableClause>())
+        .is_some()
+}
+
+/// Whether `reference` is used somewhere that inlining its value would be unsafe or change
+/// behavior: a `export { FOO }` / `export { FOO as Bar }` re-export, or an object member where
+/// the identifier is the property name, either as a shorthand member (`{ FOO }`) or as the name
+/// of a regular property member. A reference used as a property value (`{ key: FOO }`) is not
+/// unsafe and gets inlined like any other.
+fn is_unsafe_reference(reference: &Reference) -> bool {
+    let node = reference.node();
+    let Some(parent) = node.parent() else {
+        return false;
+    };
+
+    matches!(
+        parent.kind(),
+        JsSyntaxKind::JS_EXPORT_NAMED_SPECIFIER | JsSyntaxKind::JS_EXPORT_NAMED_SHORTHAND_SPECIFIER
+    ) || JsShorthandPropertyObjectMember::cast_ref(&parent).is_some()
+        || JsPropertyObjectMember::cast_ref(&parent).map_or(false, |member| {
+            member
+                .name()
+                .ok()
+                .map_or(false, |name| name.syntax() == &node)
+        })
+}
+
 pub struct State {
-    literal: JsStringLiteralExpression,
+    literal: JsAnyLiteralExpression,
     references: Vec<Reference>,
+    is_safe_to_fix: bool,
 }
 
 impl Rule for NoShoutyConstants {
@@ -111,6 +171,7 @@ impl Rule for NoShoutyConstants {
     type Query = Semantic<JsVariableDeclarator>;
     type State = State;
     type Signals = Option<Self::State>;
+    type Options = NoShoutyConstantsOptions;
 
     fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
         let declarator = ctx.query();
@@ -119,11 +180,22 @@ impl Rule for NoShoutyConstants {
             .parent::<JsVariableDeclaration>()?;
 
         if declaration.is_const() {
-            if let Some((binding, literal)) = is_id_and_string_literal_inner_text_equal(declarator)
-            {
+            if let Some((binding, literal)) = is_id_and_literal_inner_text_equal(
+                declarator,
+                ctx.options().check_all_literals,
+            ) {
+                let references: Vec<Reference> = binding.all_references(ctx.model()).collect();
+                // Only a string literal can be splatted back at each reference site; numeric and
+                // boolean matches (`check_all_literals`) are still reported, but never get an
+                // autofix.
+                let is_safe_to_fix = matches!(literal, JsAnyLiteralExpression::JsStringLiteralExpression(_))
+                    && !is_exported(declarator)
+                    && !references.iter().any(is_unsafe_reference);
+
                 return Some(State {
                     literal,
-                    references: binding.all_references(ctx.model()).collect(),
+                    references,
+                    is_safe_to_fix,
                 });
             }
         }
@@ -156,8 +228,15 @@ impl Rule for NoShoutyConstants {
     }
 
     fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        // Inlining would change the module's public surface (`export const FOO = "FOO"`) or
+        // break a use that relies on the binding's name (a re-export or an object property),
+        // so we only ever offer the fix when none of those apply.
+        if !state.is_safe_to_fix {
+            return None;
+        }
+
         let root = ctx.root();
-        let literal = JsAnyLiteralExpression::JsStringLiteralExpression(state.literal.clone());
+        let literal = state.literal.clone();
 
         let mut batch = root.begin();
 
@@ -182,4 +261,4 @@ impl Rule for NoShoutyConstants {
             mutation: batch,
         })
     }
-}
\ No newline at end of file
+}